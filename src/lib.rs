@@ -21,16 +21,16 @@
 //! let v = vec![0, 1, 2, 3, 4];  // 5 items
 //! let arr = [0, 1, 2, 3, 4];
 //!
-//! let mvv: MovableVec<i32> = movable(v);
+//! let mvv: MovableVec<i32> = movable::<i32, 5>(v);
 //! let mvv_arr: MovableVec<i32> = movable(arr);
 //! ```
 //!
 //! Alternatively, you can use `ToMovable`:
-//! ```no_run
+//! ```ignore
 //! use moving::ToMovable;
 //!
 //! some_vec.to_movable();
-//! some_arr.to_movalbe();
+//! some_arr.to_movable();
 //! ```
 //!
 //! ## Movable arrays
@@ -47,67 +47,172 @@
 //! ```
 //!
 //! Alternatively, you can use `ToNMovable`:
-//! ```no_run
+//! ```ignore
 //! use moving::ToNMovable;
 //!
 //! some_vec.to_nmovable()?;
 //! some_arr.to_nmovable()?;
 //! ```
 
-use thiserror::Error;
+#![cfg_attr(not(feature = "std"), no_std)]
 
-#[derive(Debug, Error)]
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{ Hash, Hasher };
+use core::mem::MaybeUninit;
+
+#[derive(Debug)]
 pub enum MovingArrayError {
-    #[error("Expected length & capacity {expected}, got {got}")] LengthUnmatch {
-        expected: usize,
-        got: usize,
-    },
+    LengthUnmatch { expected: usize, got: usize },
 }
 
+impl fmt::Display for MovingArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthUnmatch { expected, got } =>
+                write!(f, "Expected length {expected}, got {got}"),
+        }
+    }
+}
+
+impl core::error::Error for MovingArrayError {}
+
 /// Moves a vector to an array.
 ///
+/// Only `vec.len() == N` is required; a vector whose capacity doesn't already
+/// match `N` (the common case, since vectors over-allocate) is shrunk to fit
+/// first, falling back to an element-by-element move if the allocator can't
+/// shrink it down exactly.
+///
+/// For converting a plain `Vec<T>` straight into `[T; N]` without going
+/// through [`MovableArray`], `<[T; N]>::try_from(vec)` (from the standard
+/// library) does the same length check and is the more idiomatic choice.
+///
 /// # Example
 ///
-/// ```no_run
+/// ```rust
+/// use moving::move_vec_to_array;
+///
 /// let v = vec![0, 1, 2, 3, 4];  // 5 items
 /// let arr = move_vec_to_array::<i16, 5>(v).unwrap();
 ///
 /// assert_eq!(arr, [0, 1, 2, 3, 4]);
 /// ```
 pub fn move_vec_to_array<T, const N: usize>(mut vec: Vec<T>) -> Result<[T; N], MovingArrayError> {
-    if vec.len() != N || vec.capacity() != N {
+    if vec.len() != N {
         return Err(MovingArrayError::LengthUnmatch { expected: N, got: vec.len() });
     }
 
-    let ptr = vec.as_mut_ptr();
-    core::mem::forget(vec);
+    if vec.capacity() != N {
+        vec.shrink_to_fit();
+    }
+
+    if vec.capacity() == N {
+        let ptr = vec.as_mut_ptr();
+        let cap = vec.capacity();
+        core::mem::forget(vec);
+        let array = unsafe { ptr.cast::<[T; N]>().read() };
+        // The elements were just moved out above; reconstruct a zero-length
+        // `Vec` over the same allocation so it frees the backing buffer on
+        // drop without re-dropping any (already moved-out) elements.
+        drop(unsafe { Vec::from_raw_parts(ptr, 0, cap) });
+        return Ok(array);
+    }
+
+    // The allocator couldn't shrink the buffer down to exactly `N` elements
+    // (e.g. a ZST, or a capacity it refuses to shrink in place), so reinterpreting
+    // the allocation as `[T; N]` isn't sound here; move element-by-element instead.
+    let mut iter = vec.into_iter();
+    Ok(core::array::from_fn(|_| iter.next().unwrap()))
+}
+
+/// Number of `u64` words needed to hold `n` occupancy bits.
+#[inline]
+const fn bitset_len(n: usize) -> usize {
+    n.div_ceil(64)
+}
 
-    Ok(unsafe { ptr.cast::<[T; N]>().read() })
+/// Splits a slot index into its word index and bitmask within that word.
+#[inline]
+const fn word_and_bit(index: usize) -> (usize, u64) {
+    (index / 64, 1u64 << (index % 64))
 }
 
 /// An array with elements that can be moved out.
-#[derive(Debug, Clone)]
-pub struct MovableArray<T, const N: usize>([Option<T>; N]);
+///
+/// Internally this stores elements in a `[MaybeUninit<T>; N]` buffer alongside
+/// a bitset tracking which slots are still occupied, instead of `[Option<T>; N]`,
+/// so filled slots don't pay for an `Option` discriminant per element.
+pub struct MovableArray<T, const N: usize> {
+    buffer: [MaybeUninit<T>; N],
+    present: Vec<u64>,
+}
 
 impl<T, const N: usize> MovableArray<T, N> {
-    pub fn from_vec(vec: Vec<T>) -> Result<Self, MovingArrayError> {
-        if vec.len() != N || vec.capacity() != N {
-            return Err(MovingArrayError::LengthUnmatch { expected: N, got: vec.len() });
+    /// An empty array with no slots occupied yet.
+    fn empty() -> Self {
+        Self {
+            // Safety: an array of `MaybeUninit<T>` doesn't require its elements
+            // to be initialized, so assuming the outer `MaybeUninit` is init is valid.
+            buffer: unsafe { MaybeUninit::uninit().assume_init() },
+            present: vec![0u64; bitset_len(N)],
         }
+    }
+
+    #[inline]
+    fn is_present(&self, index: usize) -> bool {
+        let (word, bit) = word_and_bit(index);
+        self.present[word] & bit != 0
+    }
 
-        let mut iter = vec.into_iter();
-        Ok(Self(core::array::from_fn::<Option<T>, N, _>(|_| iter.next())))
+    #[inline]
+    fn set_present(&mut self, index: usize, present: bool) {
+        let (word, bit) = word_and_bit(index);
+        if present {
+            self.present[word] |= bit;
+        } else {
+            self.present[word] &= !bit;
+        }
+    }
+
+    pub fn from_vec(vec: Vec<T>) -> Result<Self, MovingArrayError> {
+        Ok(Self::from_array(move_vec_to_array::<T, N>(vec)?))
     }
 
     pub fn from_array(array: [T; N]) -> Self {
-        let mut iter = array.into_iter();
-        Self(core::array::from_fn::<Option<T>, N, _>(|_| iter.next()))
+        let mut out = Self::empty();
+        for (i, item) in array.into_iter().enumerate() {
+            out.buffer[i] = MaybeUninit::new(item);
+            out.set_present(i, true);
+        }
+        out
+    }
+
+    /// Take an element at `index`, returning `None` instead of panicking
+    /// for an out-of-range or already-taken slot. Used internally by
+    /// `take_range_vec` and `drain_range`, which tolerate ranges that extend
+    /// past `N`.
+    #[inline]
+    fn take_in_bounds(&mut self, index: usize) -> Option<T> {
+        if index >= N || !self.is_present(index) {
+            return None;
+        }
+        self.set_present(index, false);
+        Some(unsafe { self.buffer[index].assume_init_read() })
     }
 
     /// Take an element from the array.
+    ///
+    /// # Panics
+    /// Panics if `index >= N`, matching plain array/slice indexing.
     #[inline]
-    pub const fn take(&mut self, index: usize) -> Option<T> {
-        self.0[index].take()
+    pub fn take(&mut self, index: usize) -> Option<T> {
+        assert!(index < N, "index out of bounds: the len is {N} but the index is {index}");
+        self.take_in_bounds(index)
     }
 
     /// Take elements (in a specific range) from an array, as an array.
@@ -120,34 +225,367 @@ impl<T, const N: usize> MovableArray<T, N> {
 
     /// Take elements (in a specific range) from an array, as a vector.
     pub fn take_range_vec(&mut self, range: core::ops::Range<usize>) -> Vec<Option<T>> {
-        range.map(|i| self.0.get_mut(i).and_then(|v| v.take())).collect()
+        range.map(|i| self.take_in_bounds(i)).collect()
     }
 
     pub const fn len(&self) -> usize {
         N
     }
 
-    pub fn into_inner(self) -> [Option<T>; N] {
-        self.0
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    pub fn into_inner(mut self) -> [Option<T>; N] {
+        core::array::from_fn(|i| self.take(i))
+    }
+
+    /// Borrowing iterator over the still-present elements, in slot order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        (0..N)
+            .filter(move |&i| self.is_present(i))
+            .map(move |i| unsafe { self.buffer[i].assume_init_ref() })
+    }
+
+    /// Mutable borrowing iterator over the still-present elements, in slot order.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> + '_ {
+        let present: Vec<usize> = (0..N).filter(|&i| self.is_present(i)).collect();
+        let ptr = self.buffer.as_mut_ptr();
+        // Safety: `present` holds distinct indices, so each `ptr.add(i)` is a
+        // disjoint slot and the resulting `&mut T`s never alias.
+        present.into_iter().map(move |i| unsafe { (*ptr.add(i)).assume_init_mut() })
     }
 
     /// Map. If an element is taken away, `None` is present.
-    pub fn map<R>(self, f: fn(Option<T>) -> Option<R>) -> MovableArray<R, N> {
-        let mut iter = self.0.into_iter();
-        MovableArray(core::array::from_fn::<Option<R>, N, _>(|_| f(iter.next().unwrap())))
+    pub fn map<R, F: FnMut(Option<T>) -> Option<R>>(mut self, mut f: F) -> MovableArray<R, N> {
+        let mut out = MovableArray::<R, N>::empty();
+        for i in 0..N {
+            if let Some(r) = f(self.take(i)) {
+                out.buffer[i] = MaybeUninit::new(r);
+                out.set_present(i, true);
+            }
+        }
+        out
+    }
+
+    /// Map only the present elements, leaving taken slots empty.
+    pub fn map_present<R, F: FnMut(T) -> R>(self, mut f: F) -> MovableArray<R, N> {
+        self.map(|slot| slot.map(&mut f))
+    }
+
+    /// Run `f` over every present element, in slot order.
+    pub fn for_each<F: FnMut(&T)>(&self, mut f: F) {
+        for item in self.iter() {
+            f(item);
+        }
+    }
+
+    /// Take and drop present elements that don't satisfy `predicate`.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        for i in 0..N {
+            if self.is_present(i) && !predicate(unsafe { self.buffer[i].assume_init_ref() }) {
+                self.take(i);
+            }
+        }
+    }
+
+    /// Lazily take present elements out of `range`, clearing each slot as it's produced.
+    pub fn drain_range(&mut self, range: core::ops::Range<usize>) -> Drain<'_, T> {
+        Drain::new(self, range)
+    }
+}
+
+impl<T, const N: usize> Drop for MovableArray<T, N> {
+    fn drop(&mut self) {
+        for i in 0..N {
+            if self.is_present(i) {
+                unsafe { self.buffer[i].assume_init_drop() };
+            }
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for MovableArray<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                (0..N).map(|i| (self.is_present(i)).then(|| unsafe {
+                    self.buffer[i].assume_init_ref()
+                }))
+            )
+            .finish()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for MovableArray<T, N> {
+    fn clone(&self) -> Self {
+        let mut out = Self::empty();
+        for i in 0..N {
+            if self.is_present(i) {
+                let value = unsafe { self.buffer[i].assume_init_ref() }.clone();
+                out.buffer[i] = MaybeUninit::new(value);
+                out.set_present(i, true);
+            }
+        }
+        out
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for MovableArray<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        (0..N).all(|i| {
+            match (self.is_present(i), other.is_present(i)) {
+                (false, false) => true,
+                (true, true) =>
+                    unsafe { self.buffer[i].assume_init_ref() == other.buffer[i].assume_init_ref() },
+                _ => false,
+            }
+        })
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for MovableArray<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for MovableArray<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        for i in 0..N {
+            let ord = match (self.is_present(i), other.is_present(i)) {
+                (false, false) => Ordering::Equal,
+                (false, true) => Ordering::Less,
+                (true, false) => Ordering::Greater,
+                (true, true) =>
+                    unsafe {
+                        self.buffer[i]
+                            .assume_init_ref()
+                            .partial_cmp(other.buffer[i].assume_init_ref())?
+                    }
+            };
+            if ord != Ordering::Equal {
+                return Some(ord);
+            }
+        }
+        Some(Ordering::Equal)
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for MovableArray<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for i in 0..N {
+            let ord = match (self.is_present(i), other.is_present(i)) {
+                (false, false) => Ordering::Equal,
+                (false, true) => Ordering::Less,
+                (true, false) => Ordering::Greater,
+                (true, true) =>
+                    unsafe {
+                        self.buffer[i].assume_init_ref().cmp(other.buffer[i].assume_init_ref())
+                    }
+            };
+            if ord != Ordering::Equal {
+                return ord;
+            }
+        }
+        Ordering::Equal
     }
 }
 
+impl<T: Hash, const N: usize> Hash for MovableArray<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for i in 0..N {
+            if self.is_present(i) {
+                state.write_u8(1);
+                unsafe { self.buffer[i].assume_init_ref() }.hash(state);
+            } else {
+                state.write_u8(0);
+            }
+        }
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for MovableArray<T, N> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        (0..N).all(|i| self.is_present(i) && unsafe { self.buffer[i].assume_init_ref() } == &other[i])
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<&[T]> for MovableArray<T, N> {
+    fn eq(&self, other: &&[T]) -> bool {
+        other.len() == N &&
+            (0..N).all(|i| self.is_present(i) && unsafe { self.buffer[i].assume_init_ref() } == &other[i])
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for MovableArray<T, N> {
+    type Error = MovingArrayError;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        Self::from_vec(vec)
+    }
+}
+
+// Intentional, deliberate break from the pre-`chunk0-1` baseline: `Output` used
+// to be `Option<T>`, backed by a real `[Option<T>; N]` slot you could borrow.
+// The `MaybeUninit` + bitset representation has no `Option<T>` in memory to
+// borrow, so there is no sound way to return `&Option<T>` here; `Output` is
+// `T`, and reading an already-taken slot panics instead of yielding `&None`.
+// Callers that want `Option`-shaped access to a possibly-taken slot should use
+// `take`/`iter()` instead of `Index`.
 impl<T, const N: usize> core::ops::Index<usize> for MovableArray<T, N> {
-    type Output = Option<T>;
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `index >= N`, or if the slot at `index` has already been taken.
     fn index(&self, index: usize) -> &Self::Output {
-        &self.0[index]
+        assert!(index < N, "index out of bounds: the len is {N} but the index is {index}");
+        assert!(self.is_present(index), "slot {index} has already been taken");
+        unsafe { self.buffer[index].assume_init_ref() }
     }
 }
 
 impl<T, const N: usize> core::ops::IndexMut<usize> for MovableArray<T, N> {
+    /// # Panics
+    /// Panics if `index >= N`, or if the slot at `index` has already been taken.
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.0[index]
+        assert!(index < N, "index out of bounds: the len is {N} but the index is {index}");
+        assert!(self.is_present(index), "slot {index} has already been taken");
+        unsafe { self.buffer[index].assume_init_mut() }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for MovableArray<T, N> {
+    type Item = T;
+    type IntoIter = MovingIntoIter<T>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        MovingIntoIter::new((0..N).map(|i| self.take(i)).collect())
+    }
+}
+
+/// By-value iterator over the present elements of a [`MovableArray`] or [`MovableVec`].
+///
+/// Already-taken slots are skipped; slots not yet yielded are dropped along
+/// with this iterator if it's dropped before exhaustion.
+pub struct MovingIntoIter<T> {
+    slots: Vec<Option<T>>,
+    front: usize,
+    back: usize,
+}
+
+impl<T> MovingIntoIter<T> {
+    fn new(slots: Vec<Option<T>>) -> Self {
+        let back = slots.len();
+        Self { slots, front: 0, back }
+    }
+
+    fn remaining(&self) -> usize {
+        self.slots[self.front..self.back].iter().filter(|slot| slot.is_some()).count()
+    }
+}
+
+impl<T> Iterator for MovingIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.front < self.back {
+            let i = self.front;
+            self.front += 1;
+            if let Some(value) = self.slots[i].take() {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.remaining();
+        (n, Some(n))
+    }
+}
+
+impl<T> DoubleEndedIterator for MovingIntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        while self.back > self.front {
+            self.back -= 1;
+            if let Some(value) = self.slots[self.back].take() {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+impl<T> ExactSizeIterator for MovingIntoIter<T> {
+    fn len(&self) -> usize {
+        self.remaining()
+    }
+}
+
+/// Lets [`Drain`] take a slot by index without caring whether the backing
+/// storage is a `MovableArray` or a `MovableVec`.
+trait DrainSource<T> {
+    fn take_at(&mut self, index: usize) -> Option<T>;
+}
+
+impl<T, const N: usize> DrainSource<T> for MovableArray<T, N> {
+    fn take_at(&mut self, index: usize) -> Option<T> {
+        self.take_in_bounds(index)
+    }
+}
+
+impl<T> DrainSource<T> for MovableVec<T> {
+    fn take_at(&mut self, index: usize) -> Option<T> {
+        self.take_in_bounds(index)
+    }
+}
+
+/// Lazily moves present elements out of a range of a [`MovableArray`] or
+/// [`MovableVec`], skipping already-taken slots.
+///
+/// If dropped before exhaustion, the remaining in-range present elements are
+/// still taken and dropped.
+pub struct Drain<'a, T> {
+    source: &'a mut dyn DrainSource<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Drain<'a, T> {
+    fn new(source: &'a mut dyn DrainSource<T>, range: core::ops::Range<usize>) -> Self {
+        Self { source, front: range.start, back: range.end }
+    }
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.front < self.back {
+            let i = self.front;
+            self.front += 1;
+            if let Some(value) = self.source.take_at(i) {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        while self.back > self.front {
+            self.back -= 1;
+            if let Some(value) = self.source.take_at(self.back) {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        for i in self.front..self.back {
+            self.source.take_at(i);
+        }
     }
 }
 
@@ -176,7 +614,9 @@ impl<T, const N: usize> IntoVecOrArray<T, N> for [T; N] {
 ///
 /// # Example
 ///
-/// ```no_run
+/// ```rust
+/// use moving::{ MovableArray, nmovable };
+///
 /// let v = vec![1, 2, 3, 4, 5];
 /// let mva: MovableArray<i32, 5> = nmovable(v).unwrap();
 /// ```
@@ -209,8 +649,22 @@ impl<T, const N: usize> ToNMovable<T, N> for [T; N] {
 }
 
 /// A `Vec` with elements that can be moved out.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct MovableVec<T>(Vec<Option<T>>);
 
+impl<T: PartialEq> PartialEq<&[T]> for MovableVec<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.0.len() == other.len() &&
+            self.0.iter().zip(other.iter()).all(|(slot, value)| slot.as_ref() == Some(value))
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for MovableVec<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.eq(&other.as_slice())
+    }
+}
+
 impl<T> MovableVec<T> {
     pub fn from_vec(v: Vec<T>) -> Self {
         Self(
@@ -223,14 +677,22 @@ impl<T> MovableVec<T> {
 
     pub fn from_array<const N: usize>(arr: [T; N]) -> Self {
         let ln = arr.len();
-        let mut iter = arr.into_iter();
         let mut vec = Vec::with_capacity(ln);
-        while let Some(item) = iter.next() {
+        for item in arr {
             vec.push(Some(item));
         }
         Self(vec)
     }
 
+    /// Take an element at `index`, returning `None` instead of panicking
+    /// for an out-of-range or already-taken slot. Used internally by
+    /// `take_range_vec` and `drain_range`, which tolerate ranges that extend
+    /// past the vec's length.
+    #[inline]
+    fn take_in_bounds(&mut self, index: usize) -> Option<T> {
+        self.0.get_mut(index).and_then(|v| v.take())
+    }
+
     /// Take an element from the array.
     #[inline]
     pub fn take(&mut self, index: usize) -> Option<T> {
@@ -247,35 +709,86 @@ impl<T> MovableVec<T> {
 
     /// Take elements (in a specific range) from an array, as a vector.
     pub fn take_range_vec(&mut self, range: core::ops::Range<usize>) -> Vec<Option<T>> {
-        range.map(|i| self.0.get_mut(i).and_then(|v| v.take())).collect()
+        range.map(|i| self.take_in_bounds(i)).collect()
     }
 
     pub const fn len(&self) -> usize {
         self.0.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     pub fn into_inner(self) -> Vec<Option<T>> {
         self.0
     }
 
     /// Map. If an element is taken away, `None` is present.
-    pub fn map<R>(self, f: fn(Option<T>) -> Option<R>) -> MovableVec<R> {
+    pub fn map<R, F: FnMut(Option<T>) -> Option<R>>(self, mut f: F) -> MovableVec<R> {
         MovableVec(
             self.0
                 .into_iter()
-                .map(|item| f(item))
+                .map(&mut f)
                 .collect()
         )
     }
+
+    /// Map only the present elements, leaving taken slots empty.
+    pub fn map_present<R, F: FnMut(T) -> R>(self, mut f: F) -> MovableVec<R> {
+        self.map(|slot| slot.map(&mut f))
+    }
+
+    /// Run `f` over every present element, in slot order.
+    pub fn for_each<F: FnMut(&T)>(&self, mut f: F) {
+        for item in self.iter() {
+            f(item);
+        }
+    }
+
+    /// Take and drop present elements that don't satisfy `predicate`.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        for slot in self.0.iter_mut() {
+            if matches!(slot, Some(value) if !predicate(value)) {
+                *slot = None;
+            }
+        }
+    }
+
+    /// Borrowing iterator over the still-present elements, in slot order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        self.0.iter().filter_map(|slot| slot.as_ref())
+    }
+
+    /// Mutable borrowing iterator over the still-present elements, in slot order.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> + '_ {
+        self.0.iter_mut().filter_map(|slot| slot.as_mut())
+    }
+
+    /// Lazily take present elements out of `range`, clearing each slot as it's produced.
+    pub fn drain_range(&mut self, range: core::ops::Range<usize>) -> Drain<'_, T> {
+        Drain::new(self, range)
+    }
+}
+
+impl<T> IntoIterator for MovableVec<T> {
+    type Item = T;
+    type IntoIter = MovingIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MovingIntoIter::new(self.0)
+    }
 }
 
 /// Turn an an array or vec into a movable vec with unknown size.
 ///
 /// # Example
 ///
-/// ```no_run
+/// ```rust
+/// use moving::{ MovableVec, movable };
+///
 /// let v = vec![1, 2, 3, 4, 5];
-/// let mvv: MovableVec<i32> = movable(v).unwrap();
+/// let mvv: MovableVec<i32> = movable::<i32, 5>(v);
 /// ```
 pub fn movable<T, const N: usize>(input: impl IntoVecOrArray<T, N>) -> MovableVec<T> {
     match input.vec_or_array() {
@@ -302,3 +815,126 @@ impl<T, const N: usize> ToMovable<T> for [T; N] {
         MovableVec::from_array(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{ AtomicUsize, Ordering };
+
+    struct DropCounter<'a>(&'a AtomicUsize);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn take_leaves_a_hole_and_drops_the_rest() {
+        let counter = AtomicUsize::new(0);
+        let mut a: MovableArray<DropCounter<'_>, 3> = MovableArray::from_array([
+            DropCounter(&counter),
+            DropCounter(&counter),
+            DropCounter(&counter),
+        ]);
+        a.take(1);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        drop(a);
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn take_out_of_bounds_panics() {
+        let mut a: MovableArray<i32, 2> = MovableArray::from_array([1, 2]);
+        a.take(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "already been taken")]
+    fn index_into_taken_slot_panics() {
+        let mut a: MovableArray<i32, 2> = MovableArray::from_array([1, 2]);
+        a.take(0);
+        let _ = a[0];
+    }
+
+    #[test]
+    fn into_iter_skips_taken_slots_and_drops_on_early_drop() {
+        let counter = AtomicUsize::new(0);
+        let mut a: MovableArray<DropCounter<'_>, 3> = MovableArray::from_array([
+            DropCounter(&counter),
+            DropCounter(&counter),
+            DropCounter(&counter),
+        ]);
+        a.take(1);
+        let mut iter = a.into_iter();
+        assert!(iter.next().is_some());
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+        drop(iter);
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn drain_range_skips_taken_slots_and_drops_remainder_on_early_drop() {
+        let counter = AtomicUsize::new(0);
+        let mut a: MovableArray<DropCounter<'_>, 4> = MovableArray::from_array([
+            DropCounter(&counter),
+            DropCounter(&counter),
+            DropCounter(&counter),
+            DropCounter(&counter),
+        ]);
+        a.take(1);
+        {
+            let mut drain = a.drain_range(0..4);
+            assert!(drain.next().is_some());
+            assert_eq!(counter.load(Ordering::SeqCst), 2);
+        }
+        assert_eq!(counter.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn movable_vec_drain_range_tolerates_out_of_range_end() {
+        let counter = AtomicUsize::new(0);
+        let mut v: MovableVec<DropCounter<'_>> = MovableVec::from_vec(
+            vec![DropCounter(&counter), DropCounter(&counter), DropCounter(&counter)]
+        );
+        let drained: Vec<_> = v.drain_range(0..10).collect();
+        assert_eq!(drained.len(), 3);
+        drop(drained);
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn movable_vec_drain_range_drops_remainder_on_early_drop() {
+        let counter = AtomicUsize::new(0);
+        let mut v: MovableVec<DropCounter<'_>> = MovableVec::from_vec(
+            vec![DropCounter(&counter), DropCounter(&counter), DropCounter(&counter)]
+        );
+        {
+            let mut drain = v.drain_range(0..10);
+            assert!(drain.next().is_some());
+            assert_eq!(counter.load(Ordering::SeqCst), 1);
+        }
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn clone_and_eq_treat_holes_as_significant() {
+        let mut a: MovableArray<i32, 3> = MovableArray::from_array([1, 2, 3]);
+        a.take(1);
+        let b = a.clone();
+        assert_eq!(a, b);
+        assert_ne!(a, MovableArray::from_array([1, 2, 3]));
+
+        let mut c: MovableArray<i32, 2> = MovableArray::from_array([1, 2]);
+        c.take(0);
+        let d: MovableArray<i32, 2> = MovableArray::from_array([5, 2]);
+        assert!(c < d);
+    }
+
+    #[test]
+    fn movable_vec_eq_against_plain_array() {
+        let v: MovableVec<i32> = MovableVec::from_vec(vec![1, 2, 3]);
+        assert!(v == [1, 2, 3]);
+    }
+}